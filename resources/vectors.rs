@@ -36,77 +36,476 @@ fn image_models() -> &'static DashMap<String, Arc<Mutex<fastembed::ImageEmbeddin
 // Model Loading Helpers
 // ============================================================================
 
-fn get_or_init_text_model(model_name: &str) -> std::result::Result<Arc<Mutex<fastembed::TextEmbedding>>, String> {
-    if let Some(entry) = text_models().get(model_name) {
+// Keyed by registered embedder name (not the fastembed model enum) so two
+// embedders can't collide even if they happen to wrap the same weights.
+fn get_or_init_text_model(embedder_name: &str, model: fastembed::EmbeddingModel) -> std::result::Result<Arc<Mutex<fastembed::TextEmbedding>>, String> {
+    if let Some(entry) = text_models().get(embedder_name) {
         return Ok(entry.value().clone());
     }
 
     let cache_dir = get_models_dir();
-    eprintln!("[yeti-vectors] Initializing text model: {} (cache: {})", model_name, cache_dir.display());
-
-    let model = fastembed::TextEmbedding::try_new(
-        fastembed::InitOptions::new(
-            parse_text_model(model_name)
-        )
-        .with_cache_dir(cache_dir)
-        .with_show_download_progress(true)
-    ).map_err(|e| format!("Failed to init text model '{}': {}", model_name, e))?;
-
-    let arc = Arc::new(Mutex::new(model));
-    text_models().insert(model_name.to_string(), arc.clone());
-    eprintln!("[yeti-vectors] Text model '{}' ready", model_name);
+    eprintln!("[yeti-vectors] Initializing text model: {} (cache: {})", embedder_name, cache_dir.display());
+
+    let loaded = fastembed::TextEmbedding::try_new(
+        fastembed::InitOptions::new(model)
+            .with_cache_dir(cache_dir)
+            .with_show_download_progress(true)
+    ).map_err(|e| format!("Failed to init text model '{}': {}", embedder_name, e))?;
+
+    let arc = Arc::new(Mutex::new(loaded));
+    text_models().insert(embedder_name.to_string(), arc.clone());
+    eprintln!("[yeti-vectors] Text model '{}' ready", embedder_name);
     Ok(arc)
 }
 
-fn get_or_init_image_model(model_name: &str) -> std::result::Result<Arc<Mutex<fastembed::ImageEmbedding>>, String> {
-    if let Some(entry) = image_models().get(model_name) {
+fn get_or_init_image_model(embedder_name: &str, model: fastembed::ImageEmbeddingModel) -> std::result::Result<Arc<Mutex<fastembed::ImageEmbedding>>, String> {
+    if let Some(entry) = image_models().get(embedder_name) {
         return Ok(entry.value().clone());
     }
 
     let cache_dir = get_models_dir();
-    eprintln!("[yeti-vectors] Initializing image model: {} (cache: {})", model_name, cache_dir.display());
-
-    let model = fastembed::ImageEmbedding::try_new(
-        fastembed::ImageInitOptions::new(
-            parse_image_model(model_name)
-        )
-        .with_cache_dir(cache_dir)
-        .with_show_download_progress(true)
-    ).map_err(|e| format!("Failed to init image model '{}': {}", model_name, e))?;
-
-    let arc = Arc::new(Mutex::new(model));
-    image_models().insert(model_name.to_string(), arc.clone());
-    eprintln!("[yeti-vectors] Image model '{}' ready", model_name);
+    eprintln!("[yeti-vectors] Initializing image model: {} (cache: {})", embedder_name, cache_dir.display());
+
+    let loaded = fastembed::ImageEmbedding::try_new(
+        fastembed::ImageInitOptions::new(model)
+            .with_cache_dir(cache_dir)
+            .with_show_download_progress(true)
+    ).map_err(|e| format!("Failed to init image model '{}': {}", embedder_name, e))?;
+
+    let arc = Arc::new(Mutex::new(loaded));
+    image_models().insert(embedder_name.to_string(), arc.clone());
+    eprintln!("[yeti-vectors] Image model '{}' ready", embedder_name);
     Ok(arc)
 }
 
 // ============================================================================
-// Model Name Parsing
+// Field Templates — compose several record fields into embedding input
 // ============================================================================
+//
+// `{{ path }}` tokens resolve against the record via dotted paths, with
+// bare numeric segments indexing into arrays (e.g. `tags.0`). Templates are
+// compiled once per distinct template string and cached, and the batch path
+// compiles before touching any record, so a malformed template fails fast
+// instead of blowing up partway through a batch.
 
-fn parse_text_model(name: &str) -> fastembed::EmbeddingModel {
-    match name {
-        "BAAI/bge-small-en-v1.5" | "bge-small-en-v1.5" => fastembed::EmbeddingModel::BGESmallENV15,
-        "BAAI/bge-base-en-v1.5" | "bge-base-en-v1.5" => fastembed::EmbeddingModel::BGEBaseENV15,
-        "BAAI/bge-large-en-v1.5" | "bge-large-en-v1.5" => fastembed::EmbeddingModel::BGELargeENV15,
-        "sentence-transformers/all-MiniLM-L6-v2" | "all-MiniLM-L6-v2" => fastembed::EmbeddingModel::AllMiniLML6V2,
-        _ => {
-            eprintln!("[yeti-vectors] Unknown text model '{}', defaulting to BGESmallENV15", name);
-            fastembed::EmbeddingModel::BGESmallENV15
+enum TemplateSegment {
+    Literal(String),
+    Path(String),
+}
+
+struct CompiledTemplate {
+    segments: Vec<TemplateSegment>,
+}
+
+fn template_cache() -> &'static DashMap<String, Arc<CompiledTemplate>> {
+    static CACHE: OnceLock<DashMap<String, Arc<CompiledTemplate>>> = OnceLock::new();
+    CACHE.get_or_init(DashMap::new)
+}
+
+// Compiles (and validates) a template once per distinct template string,
+// same cache-by-key pattern as `text_models`/`embedder_registry`, so
+// repeated single-record calls don't re-parse it from scratch.
+fn compiled_template_for(template: &str) -> std::result::Result<Arc<CompiledTemplate>, String> {
+    if let Some(entry) = template_cache().get(template) {
+        return Ok(entry.value().clone());
+    }
+
+    let compiled = Arc::new(compile_template(template)?);
+    template_cache().insert(template.to_string(), compiled.clone());
+    Ok(compiled)
+}
+
+fn compile_template(template: &str) -> std::result::Result<CompiledTemplate, String> {
+    if template.matches("{{").count() != template.matches("}}").count() {
+        return Err(format!("Unbalanced template braces in '{}'", template));
+    }
+
+    let mut segments = Vec::new();
+    let mut rest = template;
+    loop {
+        let Some(start) = rest.find("{{") else {
+            if !rest.is_empty() {
+                segments.push(TemplateSegment::Literal(rest.to_string()));
+            }
+            break;
+        };
+
+        if start > 0 {
+            segments.push(TemplateSegment::Literal(rest[..start].to_string()));
+        }
+
+        let after_open = &rest[start + 2..];
+        let end = after_open.find("}}")
+            .ok_or_else(|| format!("Unbalanced template braces in '{}'", template))?;
+
+        let token = after_open[..end].trim();
+        if token.is_empty() {
+            return Err(format!("Empty template placeholder in '{}'", template));
         }
+        if !token.chars().all(|c| c.is_alphanumeric() || c == '_' || c == '.') {
+            return Err(format!(
+                "Unknown template helper or invalid path '{{{{{}}}}}' in '{}'", token, template
+            ));
+        }
+
+        segments.push(TemplateSegment::Path(token.to_string()));
+        rest = &after_open[end + 2..];
+    }
+
+    Ok(CompiledTemplate { segments })
+}
+
+fn resolve_path<'v>(record: &'v serde_json::Value, path: &str) -> Option<&'v serde_json::Value> {
+    let mut current = record;
+    for segment in path.split('.') {
+        current = if let Ok(index) = segment.parse::<usize>() {
+            current.as_array()?.get(index)?
+        } else {
+            current.as_object()?.get(segment)?
+        };
     }
+    Some(current)
 }
 
-fn parse_image_model(name: &str) -> fastembed::ImageEmbeddingModel {
+fn stringify_value(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::Null => String::new(),
+        serde_json::Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+fn render_template(compiled: &CompiledTemplate, record: &serde_json::Value) -> String {
+    let mut out = String::new();
+    for segment in &compiled.segments {
+        match segment {
+            TemplateSegment::Literal(s) => out.push_str(s),
+            TemplateSegment::Path(path) => {
+                if let Some(value) = resolve_path(record, path) {
+                    out.push_str(&stringify_value(value));
+                }
+            }
+        }
+    }
+    out
+}
+
+// Resolves the text a mapping should embed: the rendered `template` when
+// set, otherwise the legacy single-`source` lookup. Returns `None` when
+// there is nothing to embed (missing/null source) so callers can skip it.
+fn render_mapping_text(
+    record: &serde_json::Value,
+    mapping: &FieldMapping,
+) -> std::result::Result<Option<String>, String> {
+    match mapping.template.as_deref() {
+        Some(template) => {
+            let compiled = compiled_template_for(template)?;
+            Ok(Some(render_template(&compiled, record)))
+        }
+        None => {
+            let Some(source_val) = record.get(&mapping.source) else {
+                return Ok(None);
+            };
+            if source_val.is_null() {
+                return Ok(None);
+            }
+            let text = source_val.as_str()
+                .ok_or_else(|| format!("Text field '{}' must be a string", mapping.source))?;
+            Ok(Some(text.to_string()))
+        }
+    }
+}
+
+// ============================================================================
+// Regenerate-aware provenance — skip re-embedding unchanged input
+// ============================================================================
+//
+// Each `{target}` field gets a `{target}__meta` sidecar recording a hash of
+// the exact embedded input and the embedder used. Subsequent runs compare
+// against this sidecar and copy the existing vector forward instead of
+// re-embedding, unless the mapping forces `regenerate`.
+
+fn stable_hash(text: &str) -> String {
+    // FNV-1a: fixed constants, so the hash is stable across processes and
+    // runs (unlike e.g. `DefaultHasher`, which is keyed per-process).
+    const FNV_OFFSET: u64 = 0xcbf29ce484222325;
+    const FNV_PRIME: u64 = 0x100000001b3;
+
+    let mut hash = FNV_OFFSET;
+    for byte in text.as_bytes() {
+        hash ^= *byte as u64;
+        hash = hash.wrapping_mul(FNV_PRIME);
+    }
+    format!("{:016x}", hash)
+}
+
+fn build_provenance_meta(input: &str, model: &str, regenerate: bool) -> serde_json::Value {
+    json!({
+        "source_hash": stable_hash(input),
+        "model": model,
+        "regenerate": regenerate,
+    })
+}
+
+fn mapping_is_up_to_date(
+    record: &serde_json::Value,
+    mapping: &FieldMapping,
+    meta_key: &str,
+    input: &str,
+) -> bool {
+    let Some(existing_vector) = record.get(&mapping.target).and_then(|v| v.as_array()) else {
+        return false;
+    };
+
+    let Some(meta) = record.get(meta_key) else {
+        return false;
+    };
+
+    let same_hash = meta.get("source_hash").and_then(|v| v.as_str()) == Some(stable_hash(input).as_str());
+    let same_model = meta.get("model").and_then(|v| v.as_str()) == Some(mapping.model.as_str());
+    // Also guard against a reconfigured embedder (same name, different
+    // declared dimensionality) leaving a stale, wrong-sized vector in place.
+    let same_dimensions = resolve_embedder(&mapping.model)
+        .map(|entry| existing_vector.len() == entry.dimensions)
+        .unwrap_or(false);
+
+    same_hash && same_model && same_dimensions
+}
+
+// ============================================================================
+// Model Name Parsing — used only while building the embedder registry, so
+// a typo in config fails fast at on_ready rather than silently defaulting.
+// ============================================================================
+
+fn parse_fastembed_text_model(name: &str) -> std::result::Result<fastembed::EmbeddingModel, String> {
+    match name {
+        "BAAI/bge-small-en-v1.5" | "bge-small-en-v1.5" => Ok(fastembed::EmbeddingModel::BGESmallENV15),
+        "BAAI/bge-base-en-v1.5" | "bge-base-en-v1.5" => Ok(fastembed::EmbeddingModel::BGEBaseENV15),
+        "BAAI/bge-large-en-v1.5" | "bge-large-en-v1.5" => Ok(fastembed::EmbeddingModel::BGELargeENV15),
+        "sentence-transformers/all-MiniLM-L6-v2" | "all-MiniLM-L6-v2" => Ok(fastembed::EmbeddingModel::AllMiniLML6V2),
+        other => Err(format!("Unknown fastembed text model '{}'", other)),
+    }
+}
+
+fn parse_fastembed_image_model(name: &str) -> std::result::Result<fastembed::ImageEmbeddingModel, String> {
     match name {
-        "clip-ViT-B-32" | "CLIP-ViT-B-32" | "clip-vit-b-32" => fastembed::ImageEmbeddingModel::ClipVitB32,
-        _ => {
-            eprintln!("[yeti-vectors] Unknown image model '{}', defaulting to ClipVitB32", name);
-            fastembed::ImageEmbeddingModel::ClipVitB32
+        "clip-ViT-B-32" | "CLIP-ViT-B-32" | "clip-vit-b-32" => Ok(fastembed::ImageEmbeddingModel::ClipVitB32),
+        other => Err(format!("Unknown fastembed image model '{}'", other)),
+    }
+}
+
+// ============================================================================
+// Embedder Registry — named embedders with declared dimensionality and
+// distance metric, populated in `on_ready` from `{root_dir}/embedders.json`.
+// ============================================================================
+//
+// `mapping.model` is resolved against this registry by name; there is no
+// fallback to a default embedder, so an unknown name is a hard error.
+
+#[derive(Clone, Copy, Debug)]
+enum DistanceMetric {
+    Cosine,
+    Dot,
+    Euclidean,
+}
+
+impl DistanceMetric {
+    fn parse(name: &str) -> std::result::Result<Self, String> {
+        match name {
+            "cosine" => Ok(Self::Cosine),
+            "dot" => Ok(Self::Dot),
+            "euclidean" => Ok(Self::Euclidean),
+            other => Err(format!("Unknown distance metric '{}'", other)),
+        }
+    }
+}
+
+#[derive(Clone)]
+enum EmbedderBackend {
+    LocalText(fastembed::EmbeddingModel),
+    LocalImage(fastembed::ImageEmbeddingModel),
+    Rest(RestEmbedderConfig),
+}
+
+#[derive(Clone)]
+struct EmbedderEntry {
+    name: String,
+    backend: EmbedderBackend,
+    dimensions: usize,
+    distance: DistanceMetric,
+}
+
+fn embedder_registry() -> &'static DashMap<String, EmbedderEntry> {
+    static REGISTRY: OnceLock<DashMap<String, EmbedderEntry>> = OnceLock::new();
+    REGISTRY.get_or_init(DashMap::new)
+}
+
+fn resolve_embedder(name: &str) -> std::result::Result<EmbedderEntry, String> {
+    embedder_registry()
+        .get(name)
+        .map(|entry| entry.value().clone())
+        .ok_or_else(|| format!("Unknown embedder '{}': no embedder registered with this name", name))
+}
+
+// Shared by vectorize_text / vectorize_fields_batch / vectorize_image so the
+// "declared vs. produced dimensions" assertion only lives in one place.
+fn check_embedding_dimensions(entry: &EmbedderEntry, embedding_len: usize) -> std::result::Result<(), String> {
+    if embedding_len != entry.dimensions {
+        return Err(format!(
+            "Embedder '{}' declares {} dimensions but produced {}", entry.name, entry.dimensions, embedding_len
+        ));
+    }
+    Ok(())
+}
+
+// Parses `{"embedders": [...]}` and registers each entry, failing fast on
+// the first invalid one rather than partially registering the set.
+fn register_embedders(config: &serde_json::Value) -> std::result::Result<(), String> {
+    let entries = config.get("embedders")
+        .and_then(|v| v.as_array())
+        .ok_or_else(|| "Embedder config must have an 'embedders' array".to_string())?;
+
+    let mut parsed = Vec::with_capacity(entries.len());
+    for entry in entries {
+        let name = entry.get("name").and_then(|v| v.as_str())
+            .ok_or_else(|| "Embedder entry missing 'name'".to_string())?;
+        let kind = entry.get("kind").and_then(|v| v.as_str()).unwrap_or("text");
+        let source = entry.get("source").and_then(|v| v.as_str()).unwrap_or("local");
+        let dimensions = entry.get("dimensions").and_then(|v| v.as_u64())
+            .ok_or_else(|| format!("Embedder '{}' missing 'dimensions'", name))? as usize;
+        let distance = DistanceMetric::parse(entry.get("distance").and_then(|v| v.as_str()).unwrap_or("cosine"))?;
+
+        let backend = match (kind, source) {
+            ("text", "rest") => {
+                let cfg: RestEmbedderConfig = serde_json::from_value(entry.clone())
+                    .map_err(|e| format!("Embedder '{}' has an invalid REST config: {}", name, e))?;
+                EmbedderBackend::Rest(cfg)
+            }
+            ("text", "local") => {
+                let model_name = entry.get("model").and_then(|v| v.as_str())
+                    .ok_or_else(|| format!("Embedder '{}' missing 'model'", name))?;
+                EmbedderBackend::LocalText(parse_fastembed_text_model(model_name)?)
+            }
+            ("image", "local") => {
+                let model_name = entry.get("model").and_then(|v| v.as_str())
+                    .ok_or_else(|| format!("Embedder '{}' missing 'model'", name))?;
+                EmbedderBackend::LocalImage(parse_fastembed_image_model(model_name)?)
+            }
+            (kind, source) => {
+                return Err(format!("Embedder '{}' has an unsupported kind/source combination '{}'/'{}'", name, kind, source));
+            }
+        };
+
+        parsed.push(EmbedderEntry {
+            name: name.to_string(),
+            backend,
+            dimensions,
+            distance,
+        });
+    }
+
+    for entry in parsed {
+        embedder_registry().insert(entry.name.clone(), entry);
+    }
+
+    Ok(())
+}
+
+// ============================================================================
+// REST Embedder Backend — OpenAI-style and Ollama-compatible /v1/embeddings
+// ============================================================================
+//
+// An alternative to the local fastembed backend for models hosted behind an
+// HTTP embeddings endpoint. Registered as an `EmbedderBackend::Rest` entry
+// in the embedder registry above and dispatched to from `vectorize_text` /
+// `vectorize_fields_batch` based on the resolved embedder's backend.
+
+#[derive(Clone, Debug, serde::Deserialize)]
+struct RestEmbedderConfig {
+    base_url: String,
+    model_id: String,
+    #[serde(default)]
+    api_key_env: Option<String>,
+    // JSON request body with "{{model}}" / "{{texts}}" sentinel strings
+    // substituted in before each call. "{{texts}}" always becomes an array of
+    // every text in the batch (e.g. OpenAI's or Ollama's `input` array); a
+    // single-string field such as Ollama's legacy `prompt` is not supported.
+    request_template: serde_json::Value,
+    // JSON pointer to the array of result items, and a pointer within each
+    // item to its float array, e.g. "/data" + "/embedding" for OpenAI.
+    response_items_pointer: String,
+    response_vector_pointer: String,
+}
+
+fn rest_client() -> &'static reqwest::blocking::Client {
+    static CLIENT: OnceLock<reqwest::blocking::Client> = OnceLock::new();
+    CLIENT.get_or_init(reqwest::blocking::Client::new)
+}
+
+fn render_rest_request_body(template: &serde_json::Value, model_id: &str, texts: &[String]) -> serde_json::Value {
+    match template {
+        serde_json::Value::String(s) if s == "{{texts}}" => {
+            serde_json::Value::Array(texts.iter().cloned().map(serde_json::Value::String).collect())
         }
+        serde_json::Value::String(s) if s == "{{model}}" => serde_json::Value::String(model_id.to_string()),
+        serde_json::Value::Object(map) => serde_json::Value::Object(
+            map.iter()
+                .map(|(k, v)| (k.clone(), render_rest_request_body(v, model_id, texts)))
+                .collect(),
+        ),
+        serde_json::Value::Array(arr) => serde_json::Value::Array(
+            arr.iter().map(|v| render_rest_request_body(v, model_id, texts)).collect(),
+        ),
+        other => other.clone(),
     }
 }
 
+// Embeds a batch of texts through a REST embedder in a single HTTP call.
+fn vectorize_texts_rest(texts: &[String], cfg: &RestEmbedderConfig) -> std::result::Result<Vec<Vec<f32>>, String> {
+    let body = render_rest_request_body(&cfg.request_template, &cfg.model_id, texts);
+
+    let mut request = rest_client().post(&cfg.base_url).json(&body);
+    if let Some(env_var) = &cfg.api_key_env {
+        let api_key = std::env::var(env_var)
+            .map_err(|_| format!("REST embedder '{}' requires env var '{}' to be set", cfg.model_id, env_var))?;
+        request = request.bearer_auth(api_key);
+    }
+
+    let response = request.send()
+        .map_err(|e| format!("Embedding request to '{}' failed: {}", cfg.base_url, e))?;
+
+    let status = response.status();
+    let body_text = response.text()
+        .map_err(|e| format!("Failed to read embedding response body from '{}': {}", cfg.base_url, e))?;
+
+    if !status.is_success() {
+        return Err(format!("Embedding API '{}' returned {}: {}", cfg.base_url, status, body_text));
+    }
+
+    let payload: serde_json::Value = serde_json::from_str(&body_text)
+        .map_err(|e| format!("Embedding response from '{}' was not valid JSON: {}", cfg.base_url, e))?;
+
+    let items = payload.pointer(&cfg.response_items_pointer)
+        .and_then(|v| v.as_array())
+        .ok_or_else(|| format!("Embedding response missing items at '{}'", cfg.response_items_pointer))?;
+
+    if items.len() != texts.len() {
+        return Err(format!(
+            "Embedding API '{}' returned {} vectors for {} input texts",
+            cfg.base_url, items.len(), texts.len()
+        ));
+    }
+
+    items.iter().map(|item| {
+        item.pointer(&cfg.response_vector_pointer)
+            .and_then(|v| v.as_array())
+            .ok_or_else(|| format!("Embedding response item missing vector at '{}'", cfg.response_vector_pointer))?
+            .iter()
+            .map(|f| f.as_f64().map(|f| f as f32).ok_or_else(|| "Embedding vector contained a non-numeric value".to_string()))
+            .collect::<std::result::Result<Vec<f32>, String>>()
+    }).collect()
+}
+
 // ============================================================================
 // FastEmbedVectorHook — implements VectorHook (sync for dylib safety)
 // ============================================================================
@@ -120,43 +519,45 @@ impl VectorHook for FastEmbedVectorHook {
         mappings: &[FieldMapping],
     ) -> std::result::Result<serde_json::Value, String> {
         for mapping in mappings {
-            let source_value = record.get(&mapping.source);
+            let is_image = mapping.field_type == "image";
 
-            // Skip if source field is null or missing
-            let Some(source_val) = source_value else {
+            // The exact input (post-template text, or the raw base64 string
+            // for images) that provenance hashing is keyed on.
+            let input = if is_image {
+                let Some(source_val) = record.get(&mapping.source) else {
+                    continue;
+                };
+                if source_val.is_null() {
+                    continue;
+                }
+                Some(source_val.as_str()
+                    .ok_or_else(|| format!("Image field '{}' must be a base64 string", mapping.source))?
+                    .to_string())
+            } else {
+                render_mapping_text(&record, mapping)?.filter(|text| !text.is_empty())
+            };
+
+            let Some(input) = input else {
                 continue;
             };
-            if source_val.is_null() {
+
+            let meta_key = format!("{}__meta", mapping.target);
+            if !mapping.regenerate.unwrap_or(false) && mapping_is_up_to_date(&record, mapping, &meta_key, &input) {
                 continue;
             }
 
-            let embedding = match mapping.field_type.as_str() {
-                "image" => {
-                    // Image: decode base64 → raw bytes → ImageEmbedding
-                    let base64_str = source_val.as_str()
-                        .ok_or_else(|| format!("Image field '{}' must be a base64 string", mapping.source))?;
-
-                    let bytes = base64::Engine::decode(
-                        &base64::engine::general_purpose::STANDARD,
-                        base64_str,
-                    ).map_err(|e| format!("Failed to decode base64 from '{}': {}", mapping.source, e))?;
-
-                    self.vectorize_image(&bytes, &mapping.model)?
-                }
-                _ => {
-                    // Text (default): read string → TextEmbedding
-                    let text = source_val.as_str()
-                        .ok_or_else(|| format!("Text field '{}' must be a string", mapping.source))?;
-
-                    if text.is_empty() {
-                        continue; // Skip empty strings
-                    }
+            let embedding = if is_image {
+                let bytes = base64::Engine::decode(
+                    &base64::engine::general_purpose::STANDARD,
+                    &input,
+                ).map_err(|e| format!("Failed to decode base64 from '{}': {}", mapping.source, e))?;
 
-                    self.vectorize_text(text, &mapping.model)?
-                }
+                self.vectorize_image(&bytes, &mapping.model)?
+            } else {
+                self.vectorize_text(&input, &mapping.model)?
             };
 
-            // Write embedding vector to target field
+            // Write embedding vector and provenance sidecar to the record
             let embedding_json: Vec<serde_json::Value> = embedding
                 .into_iter()
                 .map(|f| serde_json::Value::from(f))
@@ -164,6 +565,7 @@ impl VectorHook for FastEmbedVectorHook {
 
             if let Some(obj) = record.as_object_mut() {
                 obj.insert(mapping.target.clone(), serde_json::Value::Array(embedding_json));
+                obj.insert(meta_key, build_provenance_meta(&input, &mapping.model, mapping.regenerate.unwrap_or(false)));
             }
         }
 
@@ -175,15 +577,31 @@ impl VectorHook for FastEmbedVectorHook {
         text: &str,
         model: &str,
     ) -> std::result::Result<Vec<f32>, String> {
-        let model_arc = get_or_init_text_model(model)?;
-        let model_guard = model_arc.lock()
-            .map_err(|e| format!("Text model mutex poisoned: {}", e))?;
+        let entry = resolve_embedder(model)?;
+
+        let embedding = match &entry.backend {
+            EmbedderBackend::Rest(cfg) => vectorize_texts_rest(&[text.to_string()], cfg)?
+                .into_iter()
+                .next()
+                .ok_or_else(|| "Text embedding returned empty result".to_string())?,
+            EmbedderBackend::LocalText(model) => {
+                let model_arc = get_or_init_text_model(&entry.name, *model)?;
+                let model_guard = model_arc.lock()
+                    .map_err(|e| format!("Text model mutex poisoned: {}", e))?;
+
+                model_guard.embed(vec![text.to_string()], None)
+                    .map_err(|e| format!("Text embedding failed: {}", e))?
+                    .into_iter().next()
+                    .ok_or_else(|| "Text embedding returned empty result".to_string())?
+            }
+            EmbedderBackend::LocalImage(_) => {
+                return Err(format!("Embedder '{}' is an image embedder, not a text embedder", entry.name));
+            }
+        };
 
-        let embeddings = model_guard.embed(vec![text.to_string()], None)
-            .map_err(|e| format!("Text embedding failed: {}", e))?;
+        check_embedding_dimensions(&entry, embedding.len())?;
 
-        embeddings.into_iter().next()
-            .ok_or_else(|| "Text embedding returned empty result".to_string())
+        Ok(embedding)
     }
 
     fn vectorize_fields_batch(
@@ -195,13 +613,22 @@ impl VectorHook for FastEmbedVectorHook {
         for mapping in mappings {
             if mapping.field_type != "text" && !mapping.field_type.is_empty() {
                 // Image fields: fall back to per-record
+                let meta_key = format!("{}__meta", mapping.target);
                 for record in &mut records {
                     if let Some(src) = record.get(&mapping.source).and_then(|v| v.as_str()) {
                         if !src.is_empty() {
-                            if let Ok(embedding) = self.vectorize_text(src, &mapping.model) {
+                            let src = src.to_string();
+                            if !mapping.regenerate.unwrap_or(false) && mapping_is_up_to_date(record, mapping, &meta_key, &src) {
+                                continue;
+                            }
+                            let embedded = base64::Engine::decode(&base64::engine::general_purpose::STANDARD, &src)
+                                .ok()
+                                .and_then(|bytes| self.vectorize_image(&bytes, &mapping.model).ok());
+                            if let Some(embedding) = embedded {
                                 let vec_json: Vec<serde_json::Value> = embedding.into_iter().map(serde_json::Value::from).collect();
                                 if let Some(obj) = record.as_object_mut() {
                                     obj.insert(mapping.target.clone(), serde_json::Value::Array(vec_json));
+                                    obj.insert(meta_key.clone(), build_provenance_meta(&src, &mapping.model, mapping.regenerate.unwrap_or(false)));
                                 }
                             }
                         }
@@ -210,14 +637,34 @@ impl VectorHook for FastEmbedVectorHook {
                 continue;
             }
 
-            // Collect (index, text) pairs for records that have the source field
+            // Resolve the (cached) compiled template, if any, once for the
+            // whole batch so a bad template fails before any record is
+            // touched, not partway through.
+            let compiled_template = mapping.template.as_deref()
+                .map(compiled_template_for)
+                .transpose()?;
+
+            let meta_key = format!("{}__meta", mapping.target);
+            let force_regenerate = mapping.regenerate.unwrap_or(false);
+
+            // Collect (index, text) pairs for records that have something to
+            // embed and aren't already up to date under the same embedder.
             let mut texts: Vec<(usize, String)> = Vec::with_capacity(records.len());
             for (i, record) in records.iter().enumerate() {
-                if let Some(val) = record.get(&mapping.source) {
-                    if let Some(text) = val.as_str() {
-                        if !text.is_empty() {
-                            texts.push((i, text.to_string()));
-                        }
+                let text = match &compiled_template {
+                    Some(compiled) => Some(render_template(compiled, record)),
+                    None => match record.get(&mapping.source) {
+                        Some(val) if !val.is_null() => Some(
+                            val.as_str()
+                                .ok_or_else(|| format!("Text field '{}' must be a string", mapping.source))?
+                                .to_string(),
+                        ),
+                        _ => None,
+                    },
+                };
+                if let Some(text) = text {
+                    if !text.is_empty() && (force_regenerate || !mapping_is_up_to_date(record, mapping, &meta_key, &text)) {
+                        texts.push((i, text));
                     }
                 }
             }
@@ -226,20 +673,30 @@ impl VectorHook for FastEmbedVectorHook {
                 continue;
             }
 
-            // Batch embed all texts in one call
-            let model_arc = get_or_init_text_model(&mapping.model)?;
-            let model_guard = model_arc.lock()
-                .map_err(|e| format!("Text model mutex poisoned: {}", e))?;
-
+            // Batch embed all texts in one call, local or REST
+            let entry = resolve_embedder(&mapping.model)?;
             let text_strings: Vec<String> = texts.iter().map(|(_, t)| t.clone()).collect();
-            let embeddings = model_guard.embed(text_strings, None)
-                .map_err(|e| format!("Batch text embedding failed: {}", e))?;
+            let embeddings = match &entry.backend {
+                EmbedderBackend::Rest(cfg) => vectorize_texts_rest(&text_strings, cfg)?,
+                EmbedderBackend::LocalText(model) => {
+                    let model_arc = get_or_init_text_model(&entry.name, *model)?;
+                    let model_guard = model_arc.lock()
+                        .map_err(|e| format!("Text model mutex poisoned: {}", e))?;
+                    model_guard.embed(text_strings, None)
+                        .map_err(|e| format!("Batch text embedding failed: {}", e))?
+                }
+                EmbedderBackend::LocalImage(_) => {
+                    return Err(format!("Embedder '{}' is an image embedder, not a text embedder", entry.name));
+                }
+            };
 
-            // Assign embeddings back to records
-            for ((idx, _), embedding) in texts.iter().zip(embeddings.into_iter()) {
+            // Assign embeddings and provenance sidecars back to records
+            for ((idx, text), embedding) in texts.iter().zip(embeddings.into_iter()) {
+                check_embedding_dimensions(&entry, embedding.len())?;
                 let vec_json: Vec<serde_json::Value> = embedding.into_iter().map(serde_json::Value::from).collect();
                 if let Some(obj) = records[*idx].as_object_mut() {
                     obj.insert(mapping.target.clone(), serde_json::Value::Array(vec_json));
+                    obj.insert(meta_key.clone(), build_provenance_meta(text, &mapping.model, force_regenerate));
                 }
             }
         }
@@ -252,18 +709,132 @@ impl VectorHook for FastEmbedVectorHook {
         bytes: &[u8],
         model: &str,
     ) -> std::result::Result<Vec<f32>, String> {
-        let model_arc = get_or_init_image_model(model)?;
+        let entry = resolve_embedder(model)?;
+
+        let EmbedderBackend::LocalImage(fastembed_model) = &entry.backend else {
+            return Err(format!("Embedder '{}' is not a local image embedder", entry.name));
+        };
+
+        let model_arc = get_or_init_image_model(&entry.name, *fastembed_model)?;
         let model_guard = model_arc.lock()
             .map_err(|e| format!("Image model mutex poisoned: {}", e))?;
 
-        let embeddings = model_guard.embed_bytes(&[bytes], None)
-            .map_err(|e| format!("Image embedding failed: {}", e))?;
+        let embedding = model_guard.embed_bytes(&[bytes], None)
+            .map_err(|e| format!("Image embedding failed: {}", e))?
+            .into_iter().next()
+            .ok_or_else(|| "Image embedding returned empty result".to_string())?;
+
+        check_embedding_dimensions(&entry, embedding.len())?;
+
+        Ok(embedding)
+    }
+}
+
+// ============================================================================
+// VectorHookExt — query-side helpers for semantic/hybrid search
+// ============================================================================
+//
+// `VectorHook` only covers embedding documents on the write path; this
+// blanket extension adds the search-side half (query embedding + hybrid
+// ranking) without requiring changes upstream in `yeti_core`.
+
+pub trait VectorHookExt: VectorHook {
+    fn vectorize_query(&self, text: &str, model: &str) -> std::result::Result<Vec<f32>, String> {
+        self.vectorize_text(text, model)
+    }
 
-        embeddings.into_iter().next()
-            .ok_or_else(|| "Image embedding returned empty result".to_string())
+    // Like `hybrid_rank`, but looks up `embedder_name` in the registry first
+    // and rejects embedders that don't declare cosine distance — the
+    // similarity formula below is cosine-specific, so a `dot`/`euclidean`
+    // embedder would otherwise silently get a meaningless blended score.
+    fn hybrid_rank_for_embedder(
+        &self,
+        embedder_name: &str,
+        query: &[f32],
+        candidates: &[(String, Vec<f32>, f32)],
+        semantic_ratio: f32,
+    ) -> std::result::Result<Vec<(String, f32)>, String> {
+        let entry = resolve_embedder(embedder_name)?;
+        if !matches!(entry.distance, DistanceMetric::Cosine) {
+            return Err(format!(
+                "Embedder '{}' declares {:?} distance, but hybrid_rank only supports cosine-distance embedders",
+                entry.name, entry.distance
+            ));
+        }
+
+        self.hybrid_rank(query, candidates, semantic_ratio)
+    }
+
+    // Reorders `candidates` (id, stored vector, keyword score) by a blend of
+    // cosine similarity to `query` and min-max-normalized keyword score.
+    // `semantic_ratio` of 0.0 returns pure keyword order untouched; 1.0
+    // returns pure semantic order.
+    fn hybrid_rank(
+        &self,
+        query: &[f32],
+        candidates: &[(String, Vec<f32>, f32)],
+        semantic_ratio: f32,
+    ) -> std::result::Result<Vec<(String, f32)>, String> {
+        let s = semantic_ratio.clamp(0.0, 1.0);
+
+        if s == 0.0 {
+            let mut ranked: Vec<(String, f32)> = candidates.iter()
+                .map(|(id, _, keyword_score)| (id.clone(), *keyword_score))
+                .collect();
+            ranked.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+            return Ok(ranked);
+        }
+
+        let semantic: Vec<f32> = candidates.iter()
+            .map(|(_, vector, _)| cosine_similarity_normalized(query, vector))
+            .collect::<std::result::Result<Vec<f32>, String>>()?;
+
+        if s == 1.0 {
+            let mut ranked: Vec<(String, f32)> = candidates.iter().zip(semantic.iter())
+                .map(|((id, _, _), sem)| (id.clone(), *sem))
+                .collect();
+            ranked.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+            return Ok(ranked);
+        }
+
+        let keyword_min = candidates.iter().map(|(_, _, k)| *k).fold(f32::INFINITY, f32::min);
+        let keyword_max = candidates.iter().map(|(_, _, k)| *k).fold(f32::NEG_INFINITY, f32::max);
+        let keyword_range = keyword_max - keyword_min;
+
+        let mut ranked: Vec<(String, f32)> = candidates.iter().zip(semantic.iter())
+            .map(|((id, _, keyword_score), sem)| {
+                let keyword_norm = if keyword_range > 0.0 { (keyword_score - keyword_min) / keyword_range } else { 0.0 };
+                let combined = (1.0 - s) * keyword_norm + s * sem;
+                (id.clone(), combined)
+            })
+            .collect();
+
+        ranked.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+        Ok(ranked)
     }
 }
 
+impl<T: VectorHook + ?Sized> VectorHookExt for T {}
+
+// Cosine similarity mapped from [-1, 1] to [0, 1]; zero-norm vectors score 0.
+fn cosine_similarity_normalized(query: &[f32], candidate: &[f32]) -> std::result::Result<f32, String> {
+    if query.len() != candidate.len() {
+        return Err(format!(
+            "Dimension mismatch in hybrid_rank: query has {} dims, candidate has {}", query.len(), candidate.len()
+        ));
+    }
+
+    let query_norm = query.iter().map(|x| x * x).sum::<f32>().sqrt();
+    let candidate_norm = candidate.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if query_norm == 0.0 || candidate_norm == 0.0 {
+        return Ok(0.0);
+    }
+
+    let dot: f32 = query.iter().zip(candidate.iter()).map(|(a, b)| a * b).sum();
+    let cosine = dot / (query_norm * candidate_norm);
+    Ok((cosine + 1.0) / 2.0)
+}
+
 // ============================================================================
 // VectorsExtension — implements Extension trait
 // ============================================================================
@@ -284,6 +855,20 @@ impl Extension for VectorsExtension {
         let dir = PathBuf::from(ctx.root_dir()).join("models");
         eprintln!("[yeti-vectors] Model cache directory: {}", dir.display());
         let _ = models_dir().set(dir);
+
+        let embedders_path = PathBuf::from(ctx.root_dir()).join("embedders.json");
+        if let Ok(contents) = std::fs::read_to_string(&embedders_path) {
+            let config: serde_json::Value = serde_json::from_str(&contents)
+                .map_err(|e| yeti_core::error::Error::msg(format!(
+                    "Failed to parse embedder config '{}': {}", embedders_path.display(), e
+                )))?;
+            register_embedders(&config)
+                .map_err(|e| yeti_core::error::Error::msg(format!(
+                    "Failed to register embedders from '{}': {}", embedders_path.display(), e
+                )))?;
+            eprintln!("[yeti-vectors] Registered {} embedder(s) from {}", embedder_registry().len(), embedders_path.display());
+        }
+
         Ok(())
     }
 }
@@ -292,3 +877,252 @@ impl Extension for VectorsExtension {
 resource!(Vectors {
     get => json!({"extension": "yeti-vectors", "status": "active"})
 });
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hybrid_rank_s_zero_is_pure_keyword_order() {
+        let hook = FastEmbedVectorHook;
+        let query = vec![1.0, 0.0];
+        let candidates = vec![
+            ("a".to_string(), vec![0.0, 1.0], 0.2),
+            ("b".to_string(), vec![1.0, 0.0], 0.9),
+            ("c".to_string(), vec![1.0, 1.0], 0.5),
+        ];
+
+        let ranked = hook.hybrid_rank(&query, &candidates, 0.0).unwrap();
+
+        let ids: Vec<&str> = ranked.iter().map(|(id, _)| id.as_str()).collect();
+        assert_eq!(ids, vec!["b", "c", "a"]);
+    }
+
+    #[test]
+    fn hybrid_rank_s_one_is_pure_semantic_order() {
+        let hook = FastEmbedVectorHook;
+        let query = vec![1.0, 0.0];
+        let candidates = vec![
+            ("a".to_string(), vec![0.0, 1.0], 0.9), // orthogonal to query, high keyword score
+            ("b".to_string(), vec![1.0, 0.0], 0.1), // same direction as query, low keyword score
+        ];
+
+        let ranked = hook.hybrid_rank(&query, &candidates, 1.0).unwrap();
+
+        assert_eq!(ranked[0].0, "b");
+        assert_eq!(ranked[1].0, "a");
+    }
+
+    #[test]
+    fn cosine_similarity_normalized_scores_zero_norm_vector_as_zero() {
+        let score = cosine_similarity_normalized(&[1.0, 0.0], &[0.0, 0.0]).unwrap();
+        assert_eq!(score, 0.0);
+    }
+
+    #[test]
+    fn cosine_similarity_normalized_rejects_dimension_mismatch() {
+        let err = cosine_similarity_normalized(&[1.0, 0.0], &[1.0, 0.0, 0.0]).unwrap_err();
+        assert!(err.contains("Dimension mismatch"));
+    }
+
+    #[test]
+    fn compile_template_rejects_unbalanced_braces() {
+        let err = compile_template("{{title} by {{author}}").unwrap_err();
+        assert!(err.contains("Unbalanced template braces"));
+    }
+
+    #[test]
+    fn render_template_mixes_literal_text_and_paths() {
+        let compiled = compile_template("{{title}} by {{author}}").unwrap();
+        let record = json!({"title": "Dune", "author": "Herbert"});
+        assert_eq!(render_template(&compiled, &record), "Dune by Herbert");
+    }
+
+    #[test]
+    fn render_template_renders_missing_or_null_path_as_empty() {
+        let compiled = compile_template("[{{missing}}][{{present}}]").unwrap();
+        let record = json!({"present": null});
+        assert_eq!(render_template(&compiled, &record), "[][]");
+    }
+
+    #[test]
+    fn render_template_resolves_nested_path_and_array_index() {
+        let compiled = compile_template("{{tags.0}} / {{author.name}}").unwrap();
+        let record = json!({"tags": ["sci-fi", "classic"], "author": {"name": "Herbert"}});
+        assert_eq!(render_template(&compiled, &record), "sci-fi / Herbert");
+    }
+
+    fn test_mapping(model: &str, regenerate: Option<bool>) -> FieldMapping {
+        FieldMapping {
+            source: "description".to_string(),
+            field_type: "text".to_string(),
+            model: model.to_string(),
+            target: "embedding".to_string(),
+            template: None,
+            regenerate,
+        }
+    }
+
+    fn register_test_embedder(name: &str, dimensions: usize) {
+        embedder_registry().insert(name.to_string(), EmbedderEntry {
+            name: name.to_string(),
+            backend: EmbedderBackend::LocalText(fastembed::EmbeddingModel::BGESmallENV15),
+            dimensions,
+            distance: DistanceMetric::Cosine,
+        });
+    }
+
+    #[test]
+    fn mapping_is_up_to_date_when_hash_and_model_match() {
+        let name = "provenance-test-match";
+        register_test_embedder(name, 3);
+        let mapping = test_mapping(name, None);
+        let record = json!({
+            "embedding": [0.1, 0.2, 0.3],
+            "embedding__meta": {"source_hash": stable_hash("hello"), "model": name, "regenerate": false},
+        });
+
+        assert!(mapping_is_up_to_date(&record, &mapping, "embedding__meta", "hello"));
+    }
+
+    #[test]
+    fn mapping_is_up_to_date_false_when_input_changed() {
+        let name = "provenance-test-changed-input";
+        register_test_embedder(name, 3);
+        let mapping = test_mapping(name, None);
+        let record = json!({
+            "embedding": [0.1, 0.2, 0.3],
+            "embedding__meta": {"source_hash": stable_hash("hello"), "model": name, "regenerate": false},
+        });
+
+        assert!(!mapping_is_up_to_date(&record, &mapping, "embedding__meta", "goodbye"));
+    }
+
+    #[test]
+    fn mapping_is_up_to_date_false_when_model_changed() {
+        let old_name = "provenance-test-old-model";
+        let new_name = "provenance-test-new-model";
+        register_test_embedder(new_name, 3);
+        let mapping = test_mapping(new_name, None);
+        let record = json!({
+            "embedding": [0.1, 0.2, 0.3],
+            "embedding__meta": {"source_hash": stable_hash("hello"), "model": old_name, "regenerate": false},
+        });
+
+        assert!(!mapping_is_up_to_date(&record, &mapping, "embedding__meta", "hello"));
+    }
+
+    #[test]
+    fn mapping_is_up_to_date_false_when_declared_dimensions_changed() {
+        let name = "provenance-test-dims-changed";
+        // Registry now declares 4 dims (e.g. the embedder was reconfigured),
+        // but the stored vector is still the old 3-dim one.
+        register_test_embedder(name, 4);
+        let mapping = test_mapping(name, None);
+        let record = json!({
+            "embedding": [0.1, 0.2, 0.3],
+            "embedding__meta": {"source_hash": stable_hash("hello"), "model": name, "regenerate": false},
+        });
+
+        assert!(!mapping_is_up_to_date(&record, &mapping, "embedding__meta", "hello"));
+    }
+
+    #[test]
+    fn mapping_is_up_to_date_mixed_batch_only_flags_changed_rows() {
+        let name = "provenance-test-mixed-batch";
+        register_test_embedder(name, 2);
+        let mapping = test_mapping(name, None);
+        let meta_key = "embedding__meta";
+
+        let fresh_meta = json!({"source_hash": stable_hash("unchanged"), "model": name, "regenerate": false});
+        let records = vec![
+            json!({"embedding": [0.1, 0.2], "embedding__meta": fresh_meta.clone()}),
+            json!({}),
+            json!({"embedding": [0.3, 0.4], "embedding__meta": fresh_meta}),
+        ];
+        let inputs = ["unchanged", "new row, never embedded", "edited row"];
+
+        let needs_embedding: Vec<bool> = records.iter().zip(inputs.iter())
+            .map(|(record, input)| !mapping_is_up_to_date(record, &mapping, meta_key, input))
+            .collect();
+
+        // Only the new and edited rows should need (re-)embedding; row
+        // indices must still line up with `inputs` for the caller to
+        // reassign results correctly.
+        assert_eq!(needs_embedding, vec![false, true, true]);
+    }
+
+    #[test]
+    fn stable_hash_is_deterministic_and_sensitive_to_input() {
+        assert_eq!(stable_hash("same"), stable_hash("same"));
+        assert_ne!(stable_hash("same"), stable_hash("different"));
+    }
+
+    #[test]
+    fn build_provenance_meta_round_trips_through_mapping_is_up_to_date() {
+        let name = "provenance-test-round-trip";
+        register_test_embedder(name, 3);
+        let mapping = test_mapping(name, None);
+        let meta = build_provenance_meta("hello", name, false);
+        let record = json!({"embedding": [0.1, 0.2, 0.3], "embedding__meta": meta});
+
+        assert!(mapping_is_up_to_date(&record, &mapping, "embedding__meta", "hello"));
+    }
+
+    #[test]
+    fn resolve_embedder_errors_on_unknown_name() {
+        let err = resolve_embedder("no-such-embedder-registered").unwrap_err();
+        assert!(err.contains("Unknown embedder"), "unexpected error: {}", err);
+    }
+
+    #[test]
+    fn check_embedding_dimensions_errors_on_mismatch() {
+        let entry = EmbedderEntry {
+            name: "dims-test".to_string(),
+            backend: EmbedderBackend::LocalText(fastembed::EmbeddingModel::BGESmallENV15),
+            dimensions: 384,
+            distance: DistanceMetric::Cosine,
+        };
+
+        assert!(check_embedding_dimensions(&entry, 384).is_ok());
+        let err = check_embedding_dimensions(&entry, 128).unwrap_err();
+        assert!(err.contains("384") && err.contains("128"), "unexpected error: {}", err);
+    }
+
+    #[test]
+    fn register_embedders_errors_on_missing_dimensions() {
+        let config = json!({
+            "embedders": [
+                {"name": "missing-dims", "kind": "text", "source": "local", "model": "bge-small-en-v1.5"},
+            ],
+        });
+
+        let err = register_embedders(&config).unwrap_err();
+        assert!(err.contains("missing-dims") && err.contains("dimensions"), "unexpected error: {}", err);
+    }
+
+    #[test]
+    fn register_embedders_errors_on_unsupported_kind_source_combination() {
+        let config = json!({
+            "embedders": [
+                {"name": "bad-combo", "kind": "image", "source": "rest", "dimensions": 512},
+            ],
+        });
+
+        let err = register_embedders(&config).unwrap_err();
+        assert!(err.contains("bad-combo") && err.contains("kind/source"), "unexpected error: {}", err);
+    }
+
+    #[test]
+    fn register_embedders_then_resolve_embedder_round_trips() {
+        let config = json!({
+            "embedders": [
+                {"name": "registry-round-trip", "kind": "text", "source": "local", "model": "bge-small-en-v1.5", "dimensions": 384, "distance": "cosine"},
+            ],
+        });
+
+        register_embedders(&config).unwrap();
+        let entry = resolve_embedder("registry-round-trip").unwrap();
+        assert_eq!(entry.dimensions, 384);
+    }
+}